@@ -4,6 +4,14 @@ use wasm_bindgen::prelude::*;
 pub struct Puzzle {
   initial_cells: utils::Cells,
   current_cells: utils::Cells,
+
+  // NOTE - Candidate masks accumulated across successive `hint` calls, so
+  // that an elimination hint actually advances: without this, `hint` would
+  // recompute the same masks from scratch every time and repeat the same
+  // elimination forever. Reset to `None` by anything that changes the board,
+  // so the next hint reseeds from the current givens and entries.
+
+  pencil_marks: Option<solver::Masks>,
 }
 
 #[wasm_bindgen]
@@ -12,20 +20,97 @@ impl Puzzle {
     let mut puzzle = Puzzle {
       initial_cells: [0; 81],
       current_cells: [0; 81],
+      pencil_marks: None,
     };
 
-    puzzle.generate();
+    puzzle.generate(logic::MEDIUM, generator::NONE);
 
     puzzle
   }
 
+  // NOTE - Parse a board from the conventional one-line format: 81 characters
+  // read row by row, digits `1`-`9` for clues and `.` or `0` for empties. The
+  // board must be well formed and internally consistent; callers can then use
+  // `is_unique` (or `count_solutions`) to learn whether it has a single answer.
+
+  pub fn from_string(input: &str) -> Result<Puzzle, JsValue> {
+    let chars: Vec<char> = input.chars().collect();
+
+    if chars.len() != 81 {
+      return Err(JsValue::from_str("expected exactly 81 characters"));
+    }
+
+    let mut cells: utils::Cells = [0; 81];
+
+    for i in 0..81 {
+      cells[i] = match chars[i] {
+        '1'..='9' => chars[i] as u8 - b'0',
+        '.' | '0' => 0,
+        _ => return Err(JsValue::from_str("invalid character in board")),
+      };
+    }
+
+    if !utils::verify(&cells) {
+      return Err(JsValue::from_str("board contains a contradiction"));
+    }
+
+    if solver::solve(cells, true, false, None).is_empty() {
+      return Err(JsValue::from_str("board has no solution"));
+    }
+
+    let mut puzzle = Puzzle {
+      initial_cells: cells,
+      current_cells: [0; 81],
+      pencil_marks: None,
+    };
+
+    puzzle.current_cells.copy_from_slice(&puzzle.initial_cells);
+
+    Ok(puzzle)
+  }
+
+  // NOTE - Render the current board in the same one-line format, using `.` for
+  // empty cells.
+
+  #[allow(clippy::inherent_to_string)]
+  pub fn to_string(&self) -> String {
+    let mut output = String::with_capacity(81);
+
+    for i in 0..81 {
+      if self.current_cells[i] == 0 {
+        output.push('.');
+      } else {
+        output.push((b'0' + self.current_cells[i]) as char);
+      }
+    }
+
+    output
+  }
+
+  pub fn is_unique(&self) -> bool {
+    solver::solve(self.initial_cells, false, true, None).len() == 1
+  }
+
+  // NOTE - How many solutions the current givens admit, counted up to `limit`.
+  // Useful for telling "unique" (1), "multiple" (>1), and "unsolvable" (0)
+  // apart when validating an imported board.
+
+  pub fn count_solutions(&self, limit: u32) -> u32 {
+    solver::count_solutions(self.initial_cells, limit)
+  }
+
   pub fn cells(&self) -> *const u8 {
     self.current_cells.as_ptr()
   }
 
-  pub fn generate(&mut self) {
-    self.initial_cells = generator::generate();
+  pub fn generate(&mut self, difficulty: u8, symmetry: u8) {
+    self.initial_cells = generator::generate(difficulty, symmetry);
     self.current_cells.copy_from_slice(&self.initial_cells);
+    self.pencil_marks = None;
+  }
+
+  pub fn difficulty(&self) -> u8 {
+    logic::difficulty(&self.initial_cells)
   }
 
   pub fn verify(&self) -> bool {
@@ -38,6 +123,159 @@ impl Puzzle {
 
     let solutions = solver::solve(grid, true, false, None);
     self.current_cells = solutions[0];
+    self.pencil_marks = None;
+  }
+
+  pub fn set_cell(&mut self, index: usize, value: u8) {
+    // NOTE - Only the empty cells of the original puzzle are the player's to
+    // fill; the givens are fixed.
+
+    // NOTE - Reject out-of-range digits: the mask logic later does
+    // `1 << (value - 1)`, which is only meaningful for `1..=9`.
+
+    if index < 81 && (1..=9).contains(&value) && self.initial_cells[index] == 0 {
+      self.current_cells[index] = value;
+      self.pencil_marks = None;
+    }
+  }
+
+  pub fn clear_cell(&mut self, index: usize) {
+    if index < 81 && self.initial_cells[index] == 0 {
+      self.current_cells[index] = 0;
+      self.pencil_marks = None;
+    }
+  }
+
+  pub fn hint(&mut self) -> Hint {
+    // NOTE - A hint only makes sense on a consistent board, so flag any
+    // contradiction in the player's entries before trying to deduce a move.
+
+    if !utils::verify(&self.current_cells) {
+      return Hint::message("contradiction");
+    }
+
+    // NOTE - Candidate masks persist across calls so an elimination hint
+    // actually sticks: without this, the next call would reseed from the
+    // current cells and rediscover the exact same elimination forever. Any
+    // method that changes the board resets `pencil_marks` to `None` so the
+    // masks get reseeded from the fresh givens and entries.
+
+    let current_cells = &self.current_cells;
+    let masks = self
+      .pencil_marks
+      .get_or_insert_with(|| logic::seed(current_cells));
+    let filled = logic::filled_from(&self.current_cells);
+
+    match logic::next_step(masks, &filled) {
+      logic::Step::Placed {
+        index,
+        value,
+        technique,
+        ..
+      } => Hint {
+        index,
+        value,
+        technique: technique.to_string(),
+        kind: "place".to_string(),
+      },
+
+      // NOTE - An elimination rules `value` out as a candidate of the cell; it
+      // must not be written into the cell the way a placement is, so the kind
+      // is surfaced explicitly for callers to branch on. Apply it to the
+      // persisted masks so the next hint sees the narrowed candidates instead
+      // of repeating this same elimination.
+      logic::Step::Eliminated {
+        index,
+        value,
+        technique,
+        ..
+      } => {
+        masks[index] &= !(1 << (value - 1));
+
+        Hint {
+          index,
+          value,
+          technique: technique.to_string(),
+          kind: "eliminate".to_string(),
+        }
+      }
+
+      logic::Step::Solved => Hint::message("solved"),
+
+      // NOTE - No purely logical step exists, so say so rather than filling a
+      // cell the player could not have deduced.
+      logic::Step::Stuck => Hint::message("guess required"),
+
+      logic::Step::Contradiction => Hint::message("contradiction"),
+    }
+  }
+}
+
+// NOTE - `Puzzle::from_string`'s error paths return a `JsValue`, which can
+// only be constructed inside an actual wasm runtime; exercising them under
+// plain `cargo test` aborts the process rather than failing the assertion.
+// The round trip below never touches that branch, so it's safe to cover
+// natively.
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn from_string_round_trips_through_to_string() {
+    let input = "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79";
+
+    let puzzle = Puzzle::from_string(input).expect("well-formed board should parse");
+
+    assert_eq!(puzzle.to_string(), input);
+  }
+}
+
+// NOTE - The next step a player could take, as surfaced to the front-end: a
+// placement or elimination carries a cell, value, and the technique that
+// justifies it; the status-only messages ("solved", "guess required",
+// "contradiction") leave the cell and value at zero.
+
+#[wasm_bindgen]
+pub struct Hint {
+  index: usize,
+  value: u8,
+  technique: String,
+  kind: String,
+}
+
+impl Hint {
+  // NOTE - A status-only hint ("solved", "guess required", "contradiction")
+  // carries no cell to act on, so its kind is "none".
+
+  fn message(technique: &str) -> Hint {
+    Hint {
+      index: 0,
+      value: 0,
+      technique: technique.to_string(),
+      kind: "none".to_string(),
+    }
+  }
+}
+
+#[wasm_bindgen]
+impl Hint {
+  pub fn index(&self) -> usize {
+    self.index
+  }
+
+  pub fn value(&self) -> u8 {
+    self.value
+  }
+
+  pub fn technique(&self) -> String {
+    self.technique.clone()
+  }
+
+  // NOTE - How the value should be applied: "place" writes it into the cell,
+  // "eliminate" removes it as a candidate, "none" accompanies a status message.
+  pub fn kind(&self) -> String {
+    self.kind.clone()
   }
 }
 
@@ -49,11 +287,68 @@ mod generator {
   use crate::solver;
   use crate::utils;
 
-  pub fn generate() -> utils::Cells {
-    let mut grid = generate_valid_grid();
-    remove_cells(&mut grid, Some(35));
+  use crate::logic;
 
-    grid
+  // NOTE - Clue-removal symmetry. `NONE` strips cells independently; `ROTATIONAL`
+  // removes a cell together with its 180° partner, giving the symmetric layouts
+  // published puzzles use.
+
+  pub const NONE: u8 = 0;
+  pub const ROTATIONAL: u8 = 1;
+
+  // NOTE - Backtrack bound for the per-cell solvability probe in
+  // `generate_valid_grid`. Generous enough that it essentially never caps a
+  // genuinely solvable branch in practice, but finite so a dead branch can't
+  // stall the fill loop while it's exhaustively proven unsolvable.
+
+  const FILL_BACKTRACK_CAP: u32 = 100;
+
+  // NOTE - Roughly how many clues each difficulty tends to leave once cells are
+  // removed. The grade is the source of truth; these targets just steer removal
+  // into the right neighbourhood so a matching puzzle turns up quickly.
+
+  fn clue_target(difficulty: u8) -> u8 {
+    match difficulty {
+      logic::EASY => 45,
+      logic::MEDIUM => 36,
+      logic::HARD => 30,
+      _ => 28,
+    }
+  }
+
+  // NOTE - How many removal orders to try against a single filled grid before
+  // giving up on it and filling a fresh one. Filling a grid is the expensive
+  // part, so reuse it across removal attempts instead of paying for a new one
+  // every time a removal order misses the target difficulty.
+
+  const REMOVAL_ATTEMPTS_PER_GRID: u32 = 50;
+  const GRID_ATTEMPTS: u32 = 20;
+
+  pub fn generate(difficulty: u8, symmetry: u8) -> utils::Cells {
+    let clues = clue_target(difficulty);
+
+    // NOTE - Removal is random, so keep producing candidates until one grades to
+    // the requested difficulty, falling back to the last candidate if the search
+    // runs long.
+
+    let mut last: Option<utils::Cells> = None;
+
+    for _ in 0..GRID_ATTEMPTS {
+      let base = generate_valid_grid();
+
+      for _ in 0..REMOVAL_ATTEMPTS_PER_GRID {
+        let mut grid = base;
+        remove_cells(&mut grid, Some(clues), symmetry);
+
+        if logic::difficulty(&grid) == difficulty {
+          return grid;
+        }
+
+        last = Some(grid);
+      }
+    }
+
+    last.unwrap_or_else(generate_valid_grid)
   }
 
   fn generate_valid_grid() -> utils::Cells {
@@ -62,52 +357,69 @@ mod generator {
     let mut grid = [0; 81];
     let mut indices: collections::HashSet<usize> = (0..81).collect();
 
-    while indices.len() > 0 {
+    while !indices.is_empty() {
       // NOTE - Pick a random cell.
 
-      let index = indices
+      let index = *indices
         .iter()
         .nth(rng.gen_range(0, indices.len()))
-        .unwrap()
-        .clone();
+        .unwrap();
 
       // NOTE - Pick a random candidate value.
 
       let candidates = utils::get_candidates(&grid, index);
-      grid[index] = candidates.choose(&mut rng).unwrap().clone();
+      grid[index] = *candidates.choose(&mut rng).unwrap();
 
-      // NOTE - Verify that we can still solve the grid.
+      // NOTE - Verify that we can still solve the grid. Bounded: an uncapped
+      // check here has to fully exhaust a dead branch before it can report
+      // unsolvable, which stalls the whole fill on a sparse grid. A false
+      // "unsolvable" just costs a reroll of this cell, not correctness.
 
-      let solutions = solver::solve(grid, true, false, Some(100));
-
-      if solutions.len() == 0 {
-        grid[index] = 0;
-      } else {
+      if solver::probe_solvable(grid, FILL_BACKTRACK_CAP) {
         indices.remove(&index);
+      } else {
+        grid[index] = 0;
       }
     }
 
     grid
   }
 
-  fn remove_cells(grid: &mut utils::Cells, desired_clue_threshold: Option<u8>) {
+  fn remove_cells(grid: &mut utils::Cells, desired_clue_threshold: Option<u8>, symmetry: u8) {
     let mut rng = rand::thread_rng();
 
-    let mut indices: Vec<usize> = (0..81).collect();
-    indices.shuffle(&mut rng);
+    // NOTE - Work in removal groups: a single cell under `NONE`, or a cell and
+    // its 180° partner `80 - i` under `ROTATIONAL`. The central cell (index 40)
+    // is its own partner, so it forms a one-cell group.
 
-    let mut counter = 0;
+    let mut groups: Vec<Vec<usize>> = if symmetry == ROTATIONAL {
+      (0..41)
+        .map(|i| if i == 80 - i { vec![i] } else { vec![i, 80 - i] })
+        .collect()
+    } else {
+      (0..81).map(|i| vec![i]).collect()
+    };
 
-    for i in indices.into_iter() {
-      let old_value = grid[i];
+    groups.shuffle(&mut rng);
 
-      grid[i] = 0;
+    let mut counter: u8 = 0;
 
-      if solver::solve(*grid, false, true, Some(100)).len() > 1 {
-        // NOTE - No longer have a unique solution, so need to revert.
-        grid[i] = old_value
+    for group in groups.into_iter() {
+      let old_values: Vec<u8> = group.iter().map(|&i| grid[i]).collect();
+
+      for &i in group.iter() {
+        grid[i] = 0;
+      }
+
+      if solver::count_solutions(*grid, 2) > 1 {
+        // NOTE - The group can't be removed without losing uniqueness, so revert
+        // all of it. The count runs with no backtrack cap: a capped search can
+        // miss a second solution on a sparse board and wrongly pass the gate.
+        for (k, &i) in group.iter().enumerate() {
+          grid[i] = old_values[k];
+        }
       } else {
-        counter += 1;
+        counter += group.len() as u8;
 
         if desired_clue_threshold.is_some() && counter >= (81 - desired_clue_threshold.unwrap()) {
           break;
@@ -115,135 +427,1243 @@ mod generator {
       }
     }
   }
+
+  #[cfg(test)]
+  mod tests {
+    use super::*;
+
+    const SOLVED: utils::Cells = [
+      5, 3, 4, 6, 7, 8, 9, 1, 2, 6, 7, 2, 1, 9, 5, 3, 4, 8, 1, 9, 8, 3, 4, 2, 5, 6, 7, 8, 5, 9, 7,
+      6, 1, 4, 2, 3, 4, 2, 6, 8, 5, 3, 7, 9, 1, 7, 1, 3, 9, 2, 4, 8, 5, 6, 9, 6, 1, 5, 3, 7, 2, 8,
+      4, 2, 8, 7, 4, 1, 9, 6, 3, 5, 3, 4, 5, 2, 8, 6, 1, 7, 9,
+    ];
+
+    #[test]
+    fn remove_cells_under_rotational_symmetry_only_empties_cells_in_180_degree_pairs() {
+      let mut grid = SOLVED;
+
+      remove_cells(&mut grid, None, ROTATIONAL);
+
+      for i in 0..81 {
+        if i == 40 {
+          continue;
+        }
+
+        assert_eq!(
+          grid[i] == 0,
+          grid[80 - i] == 0,
+          "cell {} and its rotational partner {} should be emptied together",
+          i,
+          80 - i
+        );
+      }
+    }
+  }
 }
 
 mod solver {
   use crate::utils;
 
-  struct Step {
-    index: usize,
-    candidates: Vec<u8>,
+  // NOTE - Candidate mask with every digit still possible. A cell mask holds a
+  // 9-bit candidate set, one bit per digit; bit `d - 1` means digit `d` is a
+  // candidate.
+
+  pub(crate) const ALL: u16 = 0x1FF;
+
+  // NOTE - Working representation used while searching: each cell is a 9-bit
+  // candidate mask rather than a resolved digit. A cell is solved exactly when
+  // its mask is a power of two, and its digit is `trailing_zeros() + 1`.
+
+  pub(crate) type Masks = [u16; 81];
+
+  // NOTE - For every cell, the indices of its 20 peers (the other cells in its
+  // row, column, and box). Computed once at compile time so constraint
+  // propagation never has to re-derive row/column/box membership.
+
+  pub(crate) const PEERS: [[usize; 20]; 81] = compute_peers();
+
+  const fn compute_peers() -> [[usize; 20]; 81] {
+    let mut peers = [[0usize; 20]; 81];
+
+    let mut index = 0;
+
+    while index < 81 {
+      let row = index / 9;
+      let column = index % 9;
+      let band = row / 3;
+      let stack = column / 3;
+
+      let mut p = 0;
+
+      // NOTE - Row peers.
+
+      let mut c = 0;
+      while c < 9 {
+        if c != column {
+          peers[index][p] = 9 * row + c;
+          p += 1;
+        }
+        c += 1;
+      }
+
+      // NOTE - Column peers.
+
+      let mut r = 0;
+      while r < 9 {
+        if r != row {
+          peers[index][p] = 9 * r + column;
+          p += 1;
+        }
+        r += 1;
+      }
+
+      // NOTE - Box peers not already covered by the row or column.
+
+      let mut br = 0;
+      while br < 3 {
+        let mut bc = 0;
+        while bc < 3 {
+          let rr = 3 * band + br;
+          let cc = 3 * stack + bc;
+
+          if rr != row && cc != column {
+            peers[index][p] = 9 * rr + cc;
+            p += 1;
+          }
+
+          bc += 1;
+        }
+        br += 1;
+      }
+
+      index += 1;
+    }
+
+    peers
   }
 
   pub fn solve(
-    mut grid: utils::Cells,
+    grid: utils::Cells,
     check_solvable: bool,
     check_unique: bool,
     backtrack_threshold: Option<u32>,
   ) -> Vec<utils::Cells> {
+    // NOTE - The two existing checks are just solution-count bounds: proving
+    // solvability needs one solution, disproving uniqueness needs two.
+
+    let max_solutions = if check_solvable {
+      Some(1)
+    } else if check_unique {
+      Some(2)
+    } else {
+      None
+    };
+
+    collect(grid, max_solutions, backtrack_threshold, true)
+  }
+
+  // NOTE - Count how many ways the board can be completed, up to `limit`. The
+  // cap keeps the search finite on near-empty grids with astronomically many
+  // solutions; the result saturates at `limit`.
+
+  pub fn count_solutions(grid: utils::Cells, limit: u32) -> u32 {
+    // NOTE - A limit of zero asks for no solutions at all; answer without
+    // searching so the count always saturates at `limit`.
+
+    if limit == 0 {
+      return 0;
+    }
+
+    collect(grid, Some(limit), None, true).len() as u32
+  }
+
+  // NOTE - A bounded existence probe for the grid-fill loop in `generator`:
+  // unlike `solve`/`count_solutions`, the backtrack cap here is allowed to
+  // trip before a solution is found. That can occasionally misreport a
+  // branch that's merely slow to search as unsolvable, but the only caller
+  // treats "can't confirm solvable" the same as "unsolvable" and just
+  // rerolls the cell, so a false negative costs a retry, not correctness.
+
+  pub(crate) fn probe_solvable(grid: utils::Cells, backtrack_threshold: u32) -> bool {
+    !collect(grid, Some(1), Some(backtrack_threshold), false).is_empty()
+  }
+
+  // NOTE - Run the backtracking search, stopping once `max_solutions` have been
+  // found (unbounded when `None`). `gate_threshold_on_solution` controls
+  // whether the backtrack cap is allowed to fire before any solution has
+  // been found (see `probe_solvable`).
+
+  fn collect(
+    grid: utils::Cells,
+    max_solutions: Option<u32>,
+    backtrack_threshold: Option<u32>,
+    gate_threshold_on_solution: bool,
+  ) -> Vec<utils::Cells> {
+    // NOTE - Seed the candidate masks from the givens. Every empty cell starts
+    // fully unconstrained; every clue is pinned to a single bit.
+
+    let mut masks: Masks = [ALL; 81];
+
+    for i in 0..81 {
+      if grid[i] != 0 {
+        masks[i] = 1 << (grid[i] - 1);
+      }
+    }
+
     let mut solutions: Vec<utils::Cells> = Vec::new();
+    let mut backtracks: u32 = 0;
 
-    let mut steps: Vec<Step> = Vec::new();
-    let mut backtracks = 0;
+    search(
+      &mut masks,
+      max_solutions,
+      backtrack_threshold,
+      gate_threshold_on_solution,
+      &mut backtracks,
+      &mut solutions,
+    );
 
-    loop {
-      if backtrack_threshold.is_some() && backtracks >= backtrack_threshold.unwrap() {
-        return solutions;
+    solutions
+  }
+
+  // NOTE - Depth-first search with constraint propagation. Returns `false` when
+  // the caller should stop exploring entirely (the solution cap or backtrack
+  // threshold fired); `true` means this subtree is exhausted but siblings
+  // should still be tried.
+
+  fn search(
+    masks: &mut Masks,
+    max_solutions: Option<u32>,
+    backtrack_threshold: Option<u32>,
+    gate_threshold_on_solution: bool,
+    backtracks: &mut u32,
+    solutions: &mut Vec<utils::Cells>,
+  ) -> bool {
+    // NOTE - Propagate naked singles to a fixed point before branching. A dead
+    // branch (some cell left with no candidates) prunes immediately.
+
+    if !propagate(masks) {
+      return true;
+    }
+
+    match select_cell(masks) {
+      None => {
+        // NOTE - Every cell is solved, so we found a solution. Record it and
+        // use the parameters to decide whether to stop.
+
+        solutions.push(decode(masks));
+
+        if let Some(max) = max_solutions {
+          if solutions.len() as u32 >= max {
+            return false;
+          }
+        }
+
+        true
       }
 
-      match generate_step(&grid) {
-        Some(mut step) => match step.candidates.pop() {
-          Some(candidate) => {
-            // NOTE - Try next candidate for this step.
+      Some(index) => {
+        // NOTE - Branch on the minimum-remaining-value cell, trying each of its
+        // remaining candidate bits in turn.
 
-            grid[step.index] = candidate;
-            steps.push(step);
+        let candidates = masks[index];
+
+        for digit in 0..9 {
+          let bit = 1 << digit;
+
+          if candidates & bit == 0 {
+            continue;
           }
 
-          None => {
-            // NOTE - No candidates left to try for this step, so back we go!
+          // NOTE - Normally the backtrack cap only bounds the tail of the search
+          // once a solution is already in hand; aborting before the first
+          // solution would let a bounded run wrongly conclude a board is
+          // unsolvable or unique, so gated callers never trip it while
+          // `solutions` is empty. `probe_solvable` opts out of that gate: it
+          // tolerates the occasional false negative in exchange for a real
+          // bound on the unsolvable case.
+
+          if (!gate_threshold_on_solution || !solutions.is_empty())
+            && backtrack_threshold.is_some()
+            && *backtracks >= backtrack_threshold.unwrap()
+          {
+            return false;
+          }
 
-            backtracks += 1;
+          *backtracks += 1;
 
-            if !try_backtrack(&mut grid, &mut steps) {
-              break;
+          let mut next = *masks;
+          next[index] = bit;
+
+          if !search(
+            &mut next,
+            max_solutions,
+            backtrack_threshold,
+            gate_threshold_on_solution,
+            backtracks,
+            solutions,
+          ) {
+            return false;
+          }
+        }
+
+        true
+      }
+    }
+  }
+
+  // NOTE - Repeatedly eliminate solved cells' digits from their peers until no
+  // further progress is made. Returns `false` if a contradiction is reached.
+
+  fn propagate(masks: &mut Masks) -> bool {
+    loop {
+      let mut progress = false;
+
+      for i in 0..81 {
+        let mask = masks[i];
+
+        if mask == 0 {
+          return false;
+        }
+
+        if !mask.is_power_of_two() {
+          continue;
+        }
+
+        for &peer in PEERS[i].iter() {
+          if masks[peer] & mask != 0 {
+            masks[peer] &= !mask;
+
+            if masks[peer] == 0 {
+              return false;
             }
+
+            progress = true;
           }
-        },
+        }
+      }
+
+      if !progress {
+        break;
+      }
+    }
+
+    true
+  }
+
+  // NOTE - Pick the unsolved cell with the fewest remaining candidates, using
+  // `count_ones` on the mask rather than rebuilding a candidate list.
+
+  fn select_cell(masks: &Masks) -> Option<usize> {
+    let mut best: Option<usize> = None;
+    let mut best_count: u32 = 10;
+
+    for (i, mask) in masks.iter().enumerate() {
+      let count = mask.count_ones();
+
+      if count > 1 && count < best_count {
+        best = Some(i);
+        best_count = count;
+
+        if count == 2 {
+          break;
+        }
+      }
+    }
+
+    best
+  }
+
+  // NOTE - Collapse a fully-solved mask grid back into resolved digits.
+
+  fn decode(masks: &Masks) -> utils::Cells {
+    let mut solution: utils::Cells = [0; 81];
+
+    for i in 0..81 {
+      solution[i] = (masks[i].trailing_zeros() + 1) as u8;
+    }
+
+    solution
+  }
+
+  #[cfg(test)]
+  mod tests {
+    use super::*;
+
+    const SOLVED: utils::Cells = [
+      5, 3, 4, 6, 7, 8, 9, 1, 2, 6, 7, 2, 1, 9, 5, 3, 4, 8, 1, 9, 8, 3, 4, 2, 5, 6, 7, 8, 5, 9, 7,
+      6, 1, 4, 2, 3, 4, 2, 6, 8, 5, 3, 7, 9, 1, 7, 1, 3, 9, 2, 4, 8, 5, 6, 9, 6, 1, 5, 3, 7, 2, 8,
+      4, 2, 8, 7, 4, 1, 9, 6, 3, 5, 3, 4, 5, 2, 8, 6, 1, 7, 9,
+    ];
+
+    #[test]
+    fn count_solutions_saturates_at_the_limit_on_an_empty_grid() {
+      // NOTE - A blank grid has far more than two solutions, so the count
+      // should stop the search at the limit rather than exhausting it.
+
+      let grid: utils::Cells = [0; 81];
+
+      assert_eq!(count_solutions(grid, 2), 2);
+    }
+
+    #[test]
+    fn count_solutions_reports_one_for_a_fully_solved_grid() {
+      assert_eq!(count_solutions(SOLVED, 5), 1);
+    }
+
+    #[test]
+    fn count_solutions_is_zero_for_a_zero_limit() {
+      // NOTE - A limit of zero asks for no solutions at all and must answer
+      // without searching, regardless of how many solutions actually exist.
+
+      let grid: utils::Cells = [0; 81];
+
+      assert_eq!(count_solutions(grid, 0), 0);
+    }
+  }
+}
+
+mod logic {
+  use crate::solver::{self, Masks, ALL, PEERS};
+  use crate::utils;
+
+  // NOTE - Difficulty labels, ordered by the hardest technique a solver is
+  // forced to reach for.
+
+  pub const EASY: u8 = 1;
+  pub const MEDIUM: u8 = 2;
+  pub const HARD: u8 = 3;
+  pub const EXPERT: u8 = 4;
+
+  // NOTE - Which cells already hold a real digit. A cell's mask can narrow to
+  // a single candidate purely as a side effect of its peers filling in,
+  // without that candidate ever having been placed, so "already handled" has
+  // to be tracked explicitly rather than inferred from mask shape or peer
+  // state (see `naked_single`).
+
+  pub(crate) type Filled = [bool; 81];
+
+  pub fn filled_from(grid: &utils::Cells) -> Filled {
+    let mut filled: Filled = [false; 81];
+
+    for (i, &value) in grid.iter().enumerate() {
+      filled[i] = value != 0;
+    }
+
+    filled
+  }
+
+  // NOTE - The nine rows, nine columns, and nine boxes, each as the indices of
+  // its member cells. Hidden singles, pairs, and box/line reductions all reason
+  // about a single unit at a time.
+
+  const UNITS: [[usize; 9]; 27] = compute_units();
+
+  const fn compute_units() -> [[usize; 9]; 27] {
+    let mut units = [[0usize; 9]; 27];
+
+    // NOTE - Rows.
+
+    let mut r = 0;
+    while r < 9 {
+      let mut c = 0;
+      while c < 9 {
+        units[r][c] = 9 * r + c;
+        c += 1;
+      }
+      r += 1;
+    }
+
+    // NOTE - Columns.
+
+    let mut c = 0;
+    while c < 9 {
+      let mut r = 0;
+      while r < 9 {
+        units[9 + c][r] = 9 * r + c;
+        r += 1;
+      }
+      c += 1;
+    }
+
+    // NOTE - Boxes.
+
+    let mut b = 0;
+    while b < 9 {
+      let band = b / 3;
+      let stack = b % 3;
+
+      let mut k = 0;
+      while k < 9 {
+        let rr = 3 * band + k / 3;
+        let cc = 3 * stack + k % 3;
+        units[18 + b][k] = 9 * rr + cc;
+        k += 1;
+      }
+
+      b += 1;
+    }
+
+    units
+  }
+
+  // NOTE - A single step the tiered solver is able to justify without guessing.
+  // Placements fill a cell; eliminations only rule a candidate out, but enable
+  // later placements. `Stuck` means no logical technique applies and a guess
+  // would be required; `Contradiction` means the board cannot be completed.
+
+  pub enum Step {
+    Placed {
+      index: usize,
+      value: u8,
+      tier: u8,
+      technique: &'static str,
+    },
+    Eliminated {
+      index: usize,
+      value: u8,
+      tier: u8,
+      technique: &'static str,
+    },
+    Solved,
+    Stuck,
+    Contradiction,
+  }
+
+  // NOTE - The outcome of grading a board: which techniques (by tier) were
+  // needed and how many outright guesses ("probes") the solver had to make.
+
+  pub struct Report {
+    pub solved: bool,
+    pub tier_counts: [u32; 4],
+    pub probes: u32,
+  }
+
+  impl Report {
+    pub fn highest_tier(&self) -> u8 {
+      let mut highest = 0;
+
+      for tier in 0..4 {
+        if self.tier_counts[tier] > 0 {
+          highest = (tier + 1) as u8;
+        }
+      }
+
+      highest
+    }
+
+    // NOTE - Collapse the tier usage and probe count into a single label.
+
+    pub fn difficulty(&self) -> u8 {
+      if self.probes > 0 {
+        return EXPERT;
+      }
+
+      // NOTE - One label per rung of the technique ladder, so the generator's
+      // distinct EASY/MEDIUM clue targets map onto distinct grades: tier 1
+      // (naked singles) is EASY, tier 2 (hidden singles) MEDIUM, and the
+      // elimination techniques of tiers 3-4 are HARD. EXPERT is reserved for
+      // boards that needed a probe, handled above.
+
+      match self.highest_tier() {
+        3 | 4 => HARD,
+        2 => MEDIUM,
+        _ => EASY,
+      }
+    }
+  }
+
+  // NOTE - Seed candidate masks from the givens and eliminate each clue's digit
+  // from its peers, which is the bookkeeping a human does before deducing
+  // anything. These eliminations are setup, not deductions, and are not graded.
+
+  pub fn seed(grid: &utils::Cells) -> Masks {
+    let mut masks: Masks = [ALL; 81];
+
+    for i in 0..81 {
+      if grid[i] != 0 {
+        masks[i] = 1 << (grid[i] - 1);
+      }
+    }
+
+    for i in 0..81 {
+      if masks[i].is_power_of_two() {
+        let bit = masks[i];
+
+        for &peer in PEERS[i].iter() {
+          masks[peer] &= !bit;
+        }
+      }
+    }
+
+    masks
+  }
+
+  // NOTE - Place a digit, mark it filled, and propagate its elimination to
+  // every peer.
+
+  pub fn place(masks: &mut Masks, filled: &mut Filled, index: usize, value: u8) {
+    let bit = 1 << (value - 1);
+    masks[index] = bit;
+    filled[index] = true;
+
+    for &peer in PEERS[index].iter() {
+      masks[peer] &= !bit;
+    }
+  }
+
+  fn digit(bit: u16) -> u8 {
+    (bit.trailing_zeros() + 1) as u8
+  }
+
+  // NOTE - Find the lowest-tier deduction currently available, preferring
+  // placements over eliminations and cheaper techniques over richer ones. Does
+  // not mutate the board; the caller applies whatever it decides to act on.
+
+  pub fn next_step(masks: &Masks, filled: &Filled) -> Step {
+    for &mask in masks.iter() {
+      if mask == 0 {
+        return Step::Contradiction;
+      }
+    }
+
+    if let Some(step) = naked_single(masks, filled) {
+      return step;
+    }
+
+    if let Some(step) = hidden_single(masks) {
+      return step;
+    }
+
+    if let Some(step) = naked_pair(masks) {
+      return step;
+    }
+
+    if let Some(step) = hidden_pair(masks) {
+      return step;
+    }
+
+    if let Some(step) = pointing(masks) {
+      return step;
+    }
+
+    if let Some(step) = box_line(masks) {
+      return step;
+    }
+
+    // NOTE - "Every mask is a singleton" is not the same as "every cell is
+    // filled": a blank cell's mask can narrow to one bit before that digit
+    // has actually been placed. `filled` is the authoritative record of what
+    // the board actually holds.
+
+    if filled.iter().all(|&is_filled| is_filled) {
+      Step::Solved
+    } else {
+      Step::Stuck
+    }
+  }
+
+  // NOTE - Tier 1: a cell left with a single candidate that hasn't been
+  // placed yet. Checked against `filled` rather than peer state: a cell can
+  // narrow to a singleton mask as a side effect of its peers filling in,
+  // with every peer already lacking that candidate, even though the cell
+  // itself was never reported as a step.
 
-        None => {
-          // NOTE - Unable to generate a new step, which means we found a
-          // solution! Add it to the list and use the parameters to determine
-          // if we can stop.
+  fn naked_single(masks: &Masks, filled: &Filled) -> Option<Step> {
+    for (i, &mask) in masks.iter().enumerate() {
+      if filled[i] || !mask.is_power_of_two() {
+        continue;
+      }
+
+      return Some(Step::Placed {
+        index: i,
+        value: digit(mask),
+        tier: 1,
+        technique: "naked single",
+      });
+    }
 
-          let mut solution: utils::Cells = [0; 81];
+    None
+  }
+
+  // NOTE - Tier 2: a digit that can legally go in only one cell of some unit.
 
-          solution.copy_from_slice(&grid);
-          solutions.push(solution);
+  fn hidden_single(masks: &Masks) -> Option<Step> {
+    for unit in UNITS.iter() {
+      for digit in 0..9 {
+        let bit = 1 << digit;
 
-          // NOTE - If we found a solution, we have proved solvability, and can
-          // stop looking.
+        let mut holder: Option<usize> = None;
+        let mut count = 0;
 
-          if check_solvable {
-            break;
+        for &cell in unit.iter() {
+          if masks[cell] & bit != 0 {
+            count += 1;
+            holder = Some(cell);
           }
-          // NOTE - If we found multiple solutions, we have disproved uniqueness, and can
-          // stop looking.
+        }
+
+        if count == 1 {
+          let cell = holder.unwrap();
 
-          if check_unique && solutions.len() > 1 {
-            break;
+          // NOTE - A cell already pinned to this digit is a naked single, not a
+          // hidden one.
+
+          if !masks[cell].is_power_of_two() {
+            return Some(Step::Placed {
+              index: cell,
+              value: (digit + 1) as u8,
+              tier: 2,
+              technique: "hidden single",
+            });
           }
+        }
+      }
+    }
 
-          // NOTE - Continue on!
+    None
+  }
+
+  // NOTE - Tier 3: two cells in a unit sharing the same two candidates pin those
+  // digits to themselves, so they can be removed from the rest of the unit.
 
-          backtracks += 1;
+  fn naked_pair(masks: &Masks) -> Option<Step> {
+    for unit in UNITS.iter() {
+      for a in 0..9 {
+        let pair = masks[unit[a]];
+
+        if pair.count_ones() != 2 {
+          continue;
+        }
 
-          if !try_backtrack(&mut grid, &mut steps) {
-            break;
+        for b in (a + 1)..9 {
+          if masks[unit[b]] != pair {
+            continue;
+          }
+
+          for &cell in unit.iter() {
+            if cell == unit[a] || cell == unit[b] {
+              continue;
+            }
+
+            if masks[cell] & pair != 0 {
+              let bit = masks[cell] & pair;
+
+              return Some(Step::Eliminated {
+                index: cell,
+                value: digit(1 << bit.trailing_zeros()),
+                tier: 3,
+                technique: "naked pair elimination",
+              });
+            }
           }
         }
       }
     }
 
-    solutions
+    None
   }
 
-  fn generate_step(grid: &utils::Cells) -> Option<Step> {
-    let first_empty_cell = grid.iter().position(|&x| x == 0);
+  // NOTE - Tier 3: two digits confined to the same two cells of a unit pin those
+  // cells to themselves, so their other candidates can be removed.
 
-    if first_empty_cell == None {
-      return None;
+  fn hidden_pair(masks: &Masks) -> Option<Step> {
+    for unit in UNITS.iter() {
+      for d1 in 0..9 {
+        let bit1 = 1 << d1;
+
+        for d2 in (d1 + 1)..9 {
+          let bit2 = 1 << d2;
+          let pair = bit1 | bit2;
+
+          let mut cells: Vec<usize> = Vec::new();
+          let mut ok = true;
+
+          for &cell in unit.iter() {
+            let has1 = masks[cell] & bit1 != 0;
+            let has2 = masks[cell] & bit2 != 0;
+
+            if has1 && has2 {
+              cells.push(cell);
+            } else if has1 || has2 {
+              // NOTE - One of the digits appears outside the candidate pair, so
+              // this is not a hidden pair.
+              ok = false;
+              break;
+            }
+          }
+
+          if !ok || cells.len() != 2 {
+            continue;
+          }
+
+          for &cell in cells.iter() {
+            if masks[cell] & !pair != 0 {
+              let bit = masks[cell] & !pair;
+
+              return Some(Step::Eliminated {
+                index: cell,
+                value: digit(1 << bit.trailing_zeros()),
+                tier: 3,
+                technique: "hidden pair elimination",
+              });
+            }
+          }
+        }
+      }
     }
 
-    let mut best_cell_index: usize = first_empty_cell.unwrap();
-    let mut best_cell_candidates: Vec<u8> = utils::get_candidates(&grid, best_cell_index);
+    None
+  }
 
-    for i in (best_cell_index + 1)..81 {
-      if grid[i] != 0 {
-        continue;
+  // NOTE - Tier 4: if every candidate for a digit inside a box lies on one row
+  // or column, the digit can be removed from the rest of that line.
+
+  fn pointing(masks: &Masks) -> Option<Step> {
+    for b in 0..9 {
+      let box_unit = &UNITS[18 + b];
+
+      for digit in 0..9 {
+        let bit = 1 << digit;
+
+        let cells: Vec<usize> = box_unit
+          .iter()
+          .cloned()
+          .filter(|&cell| masks[cell] & bit != 0)
+          .collect();
+
+        if cells.len() < 2 {
+          continue;
+        }
+
+        let same_row = cells.iter().all(|&cell| cell / 9 == cells[0] / 9);
+        let same_column = cells.iter().all(|&cell| cell % 9 == cells[0] % 9);
+
+        let line: Option<&[usize; 9]> = if same_row {
+          Some(&UNITS[cells[0] / 9])
+        } else if same_column {
+          Some(&UNITS[9 + cells[0] % 9])
+        } else {
+          None
+        };
+
+        if let Some(line) = line {
+          for &cell in line.iter() {
+            if cell / 9 / 3 == box_unit[0] / 9 / 3 && cell % 9 / 3 == box_unit[0] % 9 / 3 {
+              continue;
+            }
+
+            if masks[cell] & bit != 0 {
+              return Some(Step::Eliminated {
+                index: cell,
+                value: (digit + 1) as u8,
+                tier: 4,
+                technique: "pointing pair",
+              });
+            }
+          }
+        }
       }
+    }
 
-      let candidates = utils::get_candidates(&grid, i);
+    None
+  }
+
+  // NOTE - Tier 4: if every candidate for a digit in a row or column lies in one
+  // box, the digit can be removed from the rest of that box.
+
+  fn box_line(masks: &Masks) -> Option<Step> {
+    for (line, line_unit) in UNITS.iter().take(18).enumerate() {
+      for digit in 0..9 {
+        let bit = 1 << digit;
+
+        let cells: Vec<usize> = line_unit
+          .iter()
+          .cloned()
+          .filter(|&cell| masks[cell] & bit != 0)
+          .collect();
+
+        if cells.len() < 2 {
+          continue;
+        }
+
+        let band = cells[0] / 9 / 3;
+        let stack = cells[0] % 9 / 3;
+
+        if cells
+          .iter()
+          .all(|&cell| cell / 9 / 3 == band && cell % 9 / 3 == stack)
+        {
+          let box_unit = &UNITS[18 + 3 * band + stack];
+
+          for &cell in box_unit.iter() {
+            if line < 9 {
+              if cell / 9 == line {
+                continue;
+              }
+            } else if cell % 9 == line - 9 {
+              continue;
+            }
 
-      if candidates.len() < best_cell_candidates.len() {
-        best_cell_index = i;
-        best_cell_candidates = candidates;
+            if masks[cell] & bit != 0 {
+              return Some(Step::Eliminated {
+                index: cell,
+                value: (digit + 1) as u8,
+                tier: 4,
+                technique: "box/line reduction",
+              });
+            }
+          }
+        }
       }
     }
 
-    Some(Step {
-      index: best_cell_index,
-      candidates: best_cell_candidates,
-    })
+    None
   }
 
-  fn try_backtrack(grid: &mut utils::Cells, steps: &mut Vec<Step>) -> bool {
+  // NOTE - Grade a board by solving it the way a human would: apply the cheapest
+  // available technique, and only guess when none apply, recording the tier of
+  // every deduction and the number of guesses.
+
+  pub fn grade(grid: &utils::Cells) -> Report {
+    let mut masks = seed(grid);
+    let mut filled = filled_from(grid);
+
+    let mut report = Report {
+      solved: false,
+      tier_counts: [0; 4],
+      probes: 0,
+    };
+
     loop {
-      match steps.pop() {
-        Some(mut step) => match step.candidates.pop() {
-          Some(candidate) => {
-            grid[step.index] = candidate;
-            steps.push(step);
+      match next_step(&masks, &filled) {
+        Step::Placed {
+          index, value, tier, ..
+        } => {
+          place(&mut masks, &mut filled, index, value);
+          report.tier_counts[(tier - 1) as usize] += 1;
+        }
+
+        Step::Eliminated {
+          index, value, tier, ..
+        } => {
+          masks[index] &= !(1 << (value - 1));
+          report.tier_counts[(tier - 1) as usize] += 1;
+        }
+
+        Step::Solved => {
+          report.solved = true;
+          break;
+        }
+
+        Step::Contradiction => {
+          break;
+        }
 
-            break true;
+        Step::Stuck => {
+          // NOTE - Logic is exhausted, so fall back to a single probe: ask the
+          // backtracking solver for the true value of the most constrained cell,
+          // fill it in, and resume deducing.
+
+          match probe(&masks) {
+            Some((index, value)) => {
+              place(&mut masks, &mut filled, index, value);
+              report.probes += 1;
+            }
+
+            None => break,
           }
+        }
+      }
+    }
+
+    report
+  }
 
-          None => grid[step.index] = 0,
-        },
+  // NOTE - Public convenience: the difficulty label for a board's givens.
 
-        None => break false,
+  pub fn difficulty(grid: &utils::Cells) -> u8 {
+    grade(grid).difficulty()
+  }
+
+  // NOTE - Resolve the minimum-remaining-value cell by deferring to the full
+  // backtracking solver, returning the cell and its value in the solution.
+
+  fn probe(masks: &Masks) -> Option<(usize, u8)> {
+    let mut target: Option<usize> = None;
+    let mut best = 10;
+
+    for (i, mask) in masks.iter().enumerate() {
+      let count = mask.count_ones();
+
+      if count > 1 && count < best {
+        best = count;
+        target = Some(i);
       }
     }
+
+    let index = target?;
+
+    let mut grid: utils::Cells = [0; 81];
+    for (i, &mask) in masks.iter().enumerate() {
+      if mask.is_power_of_two() {
+        grid[i] = (mask.trailing_zeros() + 1) as u8;
+      }
+    }
+
+    let solutions = solver::solve(grid, true, false, None);
+
+    if solutions.is_empty() {
+      return None;
+    }
+
+    Some((index, solutions[0][index]))
+  }
+
+  // NOTE - Fixture boards for each technique tier, built directly in mask
+  // space so each test isolates the one deduction it names rather than
+  // depending on a full puzzle happening to reach it first.
+
+  #[cfg(test)]
+  mod tests {
+    use super::*;
+
+    #[test]
+    fn naked_single_finds_the_forced_digit() {
+      let mut masks: Masks = [ALL; 81];
+      masks[0] = 1 << 0;
+
+      let filled: Filled = [false; 81];
+
+      match naked_single(&masks, &filled) {
+        Some(Step::Placed {
+          index,
+          value,
+          tier,
+          technique,
+        }) => {
+          assert_eq!(index, 0);
+          assert_eq!(value, 1);
+          assert_eq!(tier, 1);
+          assert_eq!(technique, "naked single");
+        }
+        _ => panic!("expected a naked single"),
+      }
+    }
+
+    #[test]
+    fn naked_single_ignores_a_cell_that_is_already_filled() {
+      let mut masks: Masks = [ALL; 81];
+      masks[0] = 1 << 0;
+
+      let mut filled: Filled = [false; 81];
+      filled[0] = true;
+
+      assert!(naked_single(&masks, &filled).is_none());
+    }
+
+    #[test]
+    fn naked_single_still_fires_when_every_peer_already_excludes_the_digit() {
+      // NOTE - Regression test: a blank cell can narrow to a single
+      // candidate purely because its peers filled in independently, leaving
+      // every peer already without that candidate. The old peer-based check
+      // mistook that for "already placed" and silently never reported it.
+
+      let mut masks: Masks = [ALL; 81];
+      masks[0] = 1 << 0;
+
+      for &peer in PEERS[0].iter() {
+        masks[peer] &= !(1 << 0);
+      }
+
+      let filled: Filled = [false; 81];
+
+      match naked_single(&masks, &filled) {
+        Some(Step::Placed { index, value, .. }) => {
+          assert_eq!(index, 0);
+          assert_eq!(value, 1);
+        }
+        _ => panic!("expected a naked single even though every peer already excludes the digit"),
+      }
+    }
+
+    #[test]
+    fn hidden_single_finds_the_only_cell_that_can_take_the_digit() {
+      let mut masks: Masks = [ALL; 81];
+
+      // NOTE - Every cell in row 0 but the first can still take every digit
+      // except 5, so cell 0 is the only place left in the row for it, even
+      // though its own mask still holds every candidate.
+
+      for mask in masks.iter_mut().take(9).skip(1) {
+        *mask &= !(1 << 4);
+      }
+
+      match hidden_single(&masks) {
+        Some(Step::Placed {
+          index,
+          value,
+          tier,
+          technique,
+        }) => {
+          assert_eq!(index, 0);
+          assert_eq!(value, 5);
+          assert_eq!(tier, 2);
+          assert_eq!(technique, "hidden single");
+        }
+        _ => panic!("expected a hidden single"),
+      }
+    }
+
+    #[test]
+    fn naked_pair_eliminates_the_shared_digits_elsewhere_in_the_unit() {
+      let mut masks: Masks = [ALL; 81];
+
+      // NOTE - Cells 0 and 1 of row 0 are pinned to the same two candidates
+      // (4 and 7), so neither digit can appear anywhere else in the row.
+
+      masks[0] = (1 << 3) | (1 << 6);
+      masks[1] = (1 << 3) | (1 << 6);
+
+      match naked_pair(&masks) {
+        Some(Step::Eliminated {
+          index,
+          value,
+          tier,
+          technique,
+        }) => {
+          assert_eq!(index, 2);
+          assert_eq!(value, 4);
+          assert_eq!(tier, 3);
+          assert_eq!(technique, "naked pair elimination");
+        }
+        _ => panic!("expected a naked pair elimination"),
+      }
+    }
+
+    #[test]
+    fn hidden_pair_eliminates_the_other_candidates_from_the_pinned_cells() {
+      let mut masks: Masks = [ALL; 81];
+
+      // NOTE - Digits 1 and 2 only fit in cells 0 and 1 of row 0, so those
+      // two cells are pinned to that pair even though each still carries an
+      // extra candidate that needs eliminating.
+
+      masks[0] = (1 << 0) | (1 << 1) | (1 << 2);
+      masks[1] = (1 << 0) | (1 << 1) | (1 << 4);
+
+      for mask in masks.iter_mut().take(9).skip(2) {
+        *mask &= !((1 << 0) | (1 << 1));
+      }
+
+      match hidden_pair(&masks) {
+        Some(Step::Eliminated {
+          index,
+          value,
+          tier,
+          technique,
+        }) => {
+          assert_eq!(index, 0);
+          assert_eq!(value, 3);
+          assert_eq!(tier, 3);
+          assert_eq!(technique, "hidden pair elimination");
+        }
+        _ => panic!("expected a hidden pair elimination"),
+      }
+    }
+
+    #[test]
+    fn pointing_eliminates_along_the_shared_line_outside_the_box() {
+      let mut masks: Masks = [ALL; 81];
+
+      // NOTE - Within box 0, digit 9 only fits in cells 0 and 1, both on row
+      // 0, so it can be ruled out of the rest of row 0 outside the box.
+
+      masks[0] = 1 << 8;
+      masks[1] = 1 << 8;
+      masks[2] = 1 << 1;
+      masks[9] = 1 << 2;
+      masks[10] = 1 << 3;
+      masks[11] = 1 << 4;
+      masks[18] = 1 << 5;
+      masks[19] = 1 << 6;
+      masks[20] = 1 << 7;
+      masks[3] = 1 << 8;
+
+      match pointing(&masks) {
+        Some(Step::Eliminated {
+          index,
+          value,
+          tier,
+          technique,
+        }) => {
+          assert_eq!(index, 3);
+          assert_eq!(value, 9);
+          assert_eq!(tier, 4);
+          assert_eq!(technique, "pointing pair");
+        }
+        _ => panic!("expected a pointing pair elimination"),
+      }
+    }
+
+    #[test]
+    fn box_line_eliminates_within_the_box_outside_the_shared_line() {
+      let mut masks: Masks = [ALL; 81];
+
+      // NOTE - Within row 0, digit 9 only fits in cells 0 and 1, both in box
+      // 0, so it can be ruled out of the rest of box 0 outside the row.
+
+      masks[0] = 1 << 8;
+      masks[1] = 1 << 8;
+      masks[2] = 1 << 0;
+      masks[3] = 1 << 1;
+      masks[4] = 1 << 2;
+      masks[5] = 1 << 3;
+      masks[6] = 1 << 4;
+      masks[7] = 1 << 5;
+      masks[8] = 1 << 6;
+      masks[9] = 1 << 8;
+      masks[10] = 1;
+      masks[11] = 1;
+      masks[18] = 1;
+      masks[19] = 1;
+      masks[20] = 1;
+
+      match box_line(&masks) {
+        Some(Step::Eliminated {
+          index,
+          value,
+          tier,
+          technique,
+        }) => {
+          assert_eq!(index, 9);
+          assert_eq!(value, 9);
+          assert_eq!(tier, 4);
+          assert_eq!(technique, "box/line reduction");
+        }
+        _ => panic!("expected a box/line reduction"),
+      }
+    }
+
+    #[test]
+    fn report_difficulty_maps_highest_tier_to_its_label() {
+      let mut report = Report {
+        solved: true,
+        tier_counts: [0; 4],
+        probes: 0,
+      };
+
+      report.tier_counts[0] = 1;
+      assert_eq!(report.difficulty(), EASY);
+
+      report.tier_counts[1] = 1;
+      assert_eq!(report.difficulty(), MEDIUM);
+
+      report.tier_counts[3] = 1;
+      assert_eq!(report.difficulty(), HARD);
+
+      report.probes = 1;
+      assert_eq!(report.difficulty(), EXPERT);
+    }
   }
 }
 